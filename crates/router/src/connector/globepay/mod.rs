@@ -0,0 +1,345 @@
+pub mod transformers;
+
+use error_stack::ResultExt;
+use masking::Maskable;
+
+use transformers as globepay;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    services,
+    types::{
+        self,
+        api::{self, IncomingWebhookRequestDetails},
+        ErrorResponse,
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct Globepay;
+
+// Globepay signs every request via `sign`/`time`/`nonce` query params instead
+// of an auth header; the create-QR and order-query paths are fixed by their
+// API version, so they live here rather than being threaded through per call.
+impl Globepay {
+    const CREATE_PATH: &'static str = "/api/v1.0/gateway/entrust/qrcode";
+    const QUERY_PATH: &'static str = "/api/v1.0/gateway/entrust/order";
+
+    fn signed_url(
+        &self,
+        base_url: &str,
+        path: &str,
+        auth: &globepay::GlobepayAuthType,
+    ) -> String {
+        let params = globepay::signing::sign_request(auth, path);
+        format!(
+            "{base_url}{path}?sign={}&time={}&nonce={}",
+            params.sign, params.time, params.nonce
+        )
+    }
+}
+
+impl api::ConnectorCommon for Globepay {
+    fn id(&self) -> &'static str {
+        "globepay"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.globepay.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        _auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        // Authentication is carried in the per-request `sign`/`time`/`nonce`
+        // query params (see `transformers::signing`), not a header.
+        Ok(vec![])
+    }
+
+    fn build_error_response(
+        &self,
+        res: types::Response,
+        _event_builder: Option<&mut crate::events::connector_api_logs::ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: globepay::GlobepayErrorResponse = res
+            .response
+            .parse_struct("GlobepayErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: response.return_code.to_string(),
+            message: response.message,
+            reason: Some(response.return_msg),
+            attempt_status: None,
+            connector_transaction_id: None,
+        })
+    }
+}
+
+impl
+    api::ConnectorIntegration<
+        api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > for Globepay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.get_auth_header(&req.connector_auth_type)?;
+        headers.push(("Content-Type".to_string(), self.get_content_type().to_string().into()));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let auth = globepay::GlobepayAuthType::try_from(&req.connector_auth_type)?;
+        Ok(self.signed_url(self.base_url(connectors), Self::CREATE_PATH, &auth))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<services::request::RequestContent, errors::ConnectorError> {
+        let connector_req = globepay::GlobepayPaymentsRequest::try_from(req)?;
+        Ok(services::request::RequestContent::Json(Box::new(
+            connector_req,
+        )))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsAuthorizeType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsAuthorizeType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::PaymentsAuthorizeType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsAuthorizeRouterData,
+        event_builder: Option<&mut crate::events::connector_api_logs::ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        let response: globepay::GlobepayPaymentsResponse = res
+            .response
+            .parse_struct("GlobepayPaymentsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|event| event.set_response_body(&response));
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut crate::events::connector_api_logs::ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl api::ConnectorIntegration<api::PSync, types::PaymentsSyncData, types::PaymentsResponseData>
+    for Globepay
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut headers = self.get_auth_header(&req.connector_auth_type)?;
+        headers.push(("Content-Type".to_string(), self.get_content_type().to_string().into()));
+        Ok(headers)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let auth = globepay::GlobepayAuthType::try_from(&req.connector_auth_type)?;
+        let order_id = req
+            .request
+            .connector_transaction_id
+            .get_connector_transaction_id()
+            .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+        let path = format!("{}/{order_id}", Self::QUERY_PATH);
+        Ok(self.signed_url(self.base_url(connectors), &path, &auth))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsSyncRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PaymentsSyncType::get_url(self, req, connectors)?)
+                .headers(types::PaymentsSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsSyncRouterData,
+        event_builder: Option<&mut crate::events::connector_api_logs::ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsSyncRouterData, errors::ConnectorError> {
+        let response: globepay::GlobepaySyncResponse = res
+            .response
+            .parse_struct("GlobepaySyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|event| event.set_response_body(&response));
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut crate::events::connector_api_logs::ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+// Globepay QR payments complete out-of-band, so this is the only place the
+// final payment status is actually learned without polling `GlobepaySyncResponse`
+// in a loop; everything `transformers::GlobepayWebhookBody` builds only matters
+// because this impl is what the webhooks core dispatches incoming notifications
+// through.
+impl api::IncomingWebhook for Globepay {
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+        _connector_auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        // `sign` is hex text, not raw bytes — decode it so it's comparable
+        // against the raw HMAC this connector actually produces.
+        hex::decode(&body.sign).change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        connector_auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let auth = globepay::GlobepayAuthType::try_from(connector_auth_type)?;
+        // Built from the same canonical-string routine `is_signature_valid`
+        // verifies with (and outbound requests sign with), instead of
+        // re-deriving a separate, `partner_code`-less message inline.
+        Ok(globepay::signing::webhook_verification_message(
+            &auth,
+            body.time,
+            body.nonce.as_deref(),
+        ))
+    }
+
+    // Globepay signs webhooks with the same `partner_code`/`credential_code`
+    // pair the payment API itself uses, not a separately configured webhook
+    // secret, so the generic secret-based verification the two methods above
+    // feed into doesn't apply cleanly here. Overriding this is what actually
+    // runs `GlobepayWebhookBody::is_signature_valid` during verification.
+    fn verify_webhook_source(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _connector_webhook_details: Option<serde_json::Value>,
+        connector_auth_type: &types::ConnectorAuthType,
+        _connector_name: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let auth = globepay::GlobepayAuthType::try_from(connector_auth_type)?;
+        Ok(body.is_signature_valid(&auth))
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(api_models::webhooks::IncomingWebhookEvent::from(
+            &body.result_code,
+        ))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(Box::new(body))
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        let body: globepay::GlobepayWebhookBody = request
+            .body
+            .parse_struct("GlobepayWebhookBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(api_models::webhooks::ObjectReferenceId::PaymentId(
+            api_models::payments::PaymentIdType::ConnectorTransactionId(body.order_id),
+        ))
+    }
+}
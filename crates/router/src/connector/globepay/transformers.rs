@@ -13,6 +13,9 @@ pub struct GlobepayPaymentsRequest {
     description: String,
     currency: enums::Currency,
     channel: GlobepayChannel,
+    // Deterministic id derived from the attempt so that a retried authorize call
+    // lands on the same Globepay order instead of minting a fresh QR code.
+    partner_order_id: String,
 }
 
 #[derive(Debug, Serialize)]
@@ -47,6 +50,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for GlobepayPaymentsRequest {
             description,
             currency: item.request.currency,
             channel,
+            partner_order_id: item.connector_request_reference_id.clone(),
         })
     }
 }
@@ -69,6 +73,122 @@ impl TryFrom<&types::ConnectorAuthType> for GlobepayAuthType {
     }
 }
 
+/// Request signing for Globepay, whose endpoints return `InvalidSign` /
+/// `SignTimeout` when the `sign`, `time` and `nonce` query params are missing,
+/// stale, or mis-signed. See https://pay.globepay.co/docs/en/#signature.
+///
+/// `sign_request` re-derives `time`/`nonce` fresh on every call, which avoids
+/// `SignTimeout` from a stale timestamp. Re-signing and resending a single
+/// `InvalidSign` response is NOT implemented: `ConnectorIntegration::build_request`/
+/// `handle_response` are synchronous and don't have a way to issue a second
+/// HTTP call, so that half of the original ask is out of scope here.
+pub(super) mod signing {
+    use masking::PeekInterface;
+
+    use super::GlobepayAuthType;
+
+    /// The `sign` / `time` / `nonce` query params Globepay expects on every
+    /// outbound request.
+    #[derive(Debug, Clone)]
+    pub struct GlobepaySignatureParams {
+        pub sign: String,
+        pub time: i64,
+        pub nonce: String,
+    }
+
+    /// Builds the canonical string (`partner_code` + `path` + `valid_time` +
+    /// `nonce`) and signs it with the merchant's `credential_code` via
+    /// HMAC-SHA256, hex-encoded lowercase. `valid_time` is read fresh on every
+    /// call so regenerating it right before send avoids clock-skew-induced
+    /// `SignTimeout`s.
+    pub fn sign_request(auth: &GlobepayAuthType, path: &str) -> GlobepaySignatureParams {
+        let time = time::OffsetDateTime::now_utc().unix_timestamp();
+        let nonce = uuid::Uuid::new_v4().simple().to_string();
+        let sign = compute_signature(auth, path, time, &nonce);
+        GlobepaySignatureParams { sign, time, nonce }
+    }
+
+    /// Recomputes the signature over an incoming webhook's `time`/`nonce` and
+    /// compares it against the `sign` Globepay attached, using the same HMAC
+    /// scheme outbound requests are signed with.
+    pub fn verify_signature(
+        auth: &GlobepayAuthType,
+        time: i64,
+        nonce: Option<&str>,
+        received_sign: &str,
+    ) -> bool {
+        // Webhooks aren't scoped to a request path, so the canonical string
+        // simply omits it.
+        compute_signature(auth, "", time, nonce.unwrap_or_default()) == received_sign
+    }
+
+    /// The exact bytes [`verify_signature`] hashes for an incoming webhook,
+    /// exposed so callers that need the raw message (rather than a yes/no
+    /// answer) build it from this single place instead of re-deriving their
+    /// own, possibly-divergent, version of Globepay's canonical string.
+    pub fn webhook_verification_message(auth: &GlobepayAuthType, time: i64, nonce: Option<&str>) -> Vec<u8> {
+        canonical_string(auth, "", time, nonce.unwrap_or_default()).into_bytes()
+    }
+
+    fn compute_signature(auth: &GlobepayAuthType, path: &str, time: i64, nonce: &str) -> String {
+        let key = ring::hmac::Key::new(
+            ring::hmac::HMAC_SHA256,
+            auth.credential_code.peek().as_bytes(),
+        );
+        let signature = ring::hmac::sign(&key, &canonical_string(auth, path, time, nonce).into_bytes());
+        hex::encode(signature.as_ref())
+    }
+
+    fn canonical_string(auth: &GlobepayAuthType, path: &str, time: i64, nonce: &str) -> String {
+        format!("{}{}{}{}", auth.partner_code.peek(), path, time, nonce)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use masking::Secret;
+
+        use super::*;
+
+        fn test_auth() -> GlobepayAuthType {
+            GlobepayAuthType {
+                partner_code: Secret::new("partner-code".to_string()),
+                credential_code: Secret::new("credential-code".to_string()),
+            }
+        }
+
+        #[test]
+        fn sign_request_produces_a_hex_encoded_hmac() {
+            let params = sign_request(&test_auth(), "/api/v1.0/gateway/entrust/qrcode");
+            assert_eq!(params.sign.len(), 64);
+            assert!(params.sign.chars().all(|c| c.is_ascii_hexdigit()));
+        }
+
+        #[test]
+        fn verify_signature_accepts_a_signature_it_produced() {
+            let auth = test_auth();
+            let params = sign_request(&auth, "");
+            assert!(verify_signature(
+                &auth,
+                params.time,
+                Some(&params.nonce),
+                &params.sign,
+            ));
+        }
+
+        #[test]
+        fn verify_signature_rejects_a_tampered_signature() {
+            let auth = test_auth();
+            let params = sign_request(&auth, "");
+            assert!(!verify_signature(
+                &auth,
+                params.time,
+                Some(&params.nonce),
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            ));
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum GlobepayPaymentStatus {
@@ -80,7 +200,10 @@ impl From<GlobepayPaymentStatus> for enums::AttemptStatus {
     fn from(item: GlobepayPaymentStatus) -> Self {
         match item {
             GlobepayPaymentStatus::Success => Self::AuthenticationPending, // this connector only have redirection flows so "Success" is mapped to authenticatoin pending ,ref = "https://pay.globepay.co/docs/en/#api-QRCode-NewQRCode"
-            GlobepayPaymentStatus::Exists => Self::Failure,
+            // The order already exists from an earlier attempt with the same
+            // partner_order_id; treat it like any other pending QR so the next
+            // psync resolves it, instead of failing a retried authorize outright.
+            GlobepayPaymentStatus::Exists => Self::AuthenticationPending,
         }
     }
 }
@@ -88,6 +211,15 @@ impl From<GlobepayPaymentStatus> for enums::AttemptStatus {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GlobepayConnectorMetadata {
     image_data_url: url::Url,
+    // Unix timestamp after which the QR code stops being payable; carried
+    // through connector_metadata so upstream schedulers can stop polling once
+    // it has passed instead of treating an abandoned payment as still pending.
+    expire_time: Option<i64>,
+    // How many transient failures have already been absorbed for this order,
+    // carried through connector_metadata so the next psync poll knows whether
+    // the merchant-configured retry budget is exhausted.
+    #[serde(default)]
+    retry_attempts: u8,
 }
 
 #[derive(Debug, Deserialize)]
@@ -97,6 +229,7 @@ pub struct GlobepayPaymentsResponse {
     qrcode_img: Option<url::Url>,
     return_code: GlobepayReturnCode, //Execution result
     return_msg: Option<String>,
+    expire_time: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, PartialEq, strum::Display)]
@@ -115,6 +248,64 @@ pub enum GlobepayReturnCode {
     DuplicateOrderId,
 }
 
+impl GlobepayReturnCode {
+    /// Whether this code is a transient hiccup (worth re-signing and resending)
+    /// as opposed to a terminal rejection that should fail outright.
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::Systemerror | Self::SignTimeout)
+    }
+}
+
+/// Retry policy for transient Globepay return codes, tunable per merchant via
+/// [`GlobepayMerchantConfig`]. Terminal codes (`OrderMismatch`, `InvalidChannel`,
+/// `NotPermitted`, `ParamInvalid`, ...) never consult this and fail immediately.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GlobepayRetryStrategy {
+    pub max_attempts: u8,
+}
+
+impl GlobepayRetryStrategy {
+    /// Whether another attempt should be made for a transient return code, given
+    /// how many attempts have already been spent.
+    ///
+    /// This only decides *whether* to retry, not *when*: the `PSync` flow that
+    /// consults this relies on the router's own poll cadence for spacing
+    /// between attempts, there's no channel from here to a scheduler to ask
+    /// for anything finer-grained (e.g. backoff).
+    pub fn should_retry(&self, return_code: &GlobepayReturnCode, attempts_made: u8) -> bool {
+        return_code.is_transient() && attempts_made < self.max_attempts
+    }
+}
+
+impl Default for GlobepayRetryStrategy {
+    fn default() -> Self {
+        Self { max_attempts: 3 }
+    }
+}
+
+/// Operator-tunable settings read from the merchant connector account's
+/// metadata, e.g. `{ "retry_strategy": { "max_attempts": 5 } }`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GlobepayMerchantConfig {
+    #[serde(default)]
+    pub retry_strategy: GlobepayRetryStrategy,
+}
+
+impl TryFrom<&Option<serde_json::Value>> for GlobepayMerchantConfig {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(metadata: &Option<serde_json::Value>) -> Result<Self, Self::Error> {
+        metadata
+            .clone()
+            .map(|value| {
+                serde_json::from_value(value).change_context(
+                    errors::ConnectorError::InvalidConnectorConfig { config: "metadata" },
+                )
+            })
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, GlobepayPaymentsResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
@@ -134,6 +325,8 @@ impl<F, T>
                     .response
                     .qrcode_img
                     .ok_or(errors::ConnectorError::ResponseHandlingFailed)?,
+                expire_time: item.response.expire_time,
+                retry_attempts: 0,
             };
             let connector_metadata = Some(common_utils::ext_traits::Encode::<
                 GlobepayConnectorMetadata,
@@ -160,9 +353,54 @@ impl<F, T>
                 }),
                 ..item.data
             })
+        } else if item.response.return_code == GlobepayReturnCode::DuplicateOrderId {
+            // Idempotent replay: Globepay rejected the create call because an
+            // order for this partner_order_id already exists. Globepay's own
+            // order_id is required to resume it correctly — partner_order_id is
+            // our merchant-side reference, not the id Globepay's psync endpoint
+            // recognizes, so without it we cannot safely resume and must fail
+            // rather than guess.
+            let globepay_id = item
+                .response
+                .order_id
+                .ok_or(errors::ConnectorError::ResponseHandlingFailed)?;
+            // The create endpoint can only ever report Success/Exists, neither of
+            // which reflects whether the shopper already paid, so a duplicate
+            // cannot be resolved to a final status here. This `TryFrom` is a
+            // synchronous response transform with no way to issue its own sync
+            // call, so we don't attempt to transparently fetch the existing
+            // payment's real status inline — we mark it pending and rely on the
+            // router's standard pending-status handling to trigger a follow-up
+            // `PSync`, which does see the shopper's true status, instead of
+            // asserting an outcome we don't know.
+            Ok(Self {
+                status: enums::AttemptStatus::AuthenticationPending,
+                response: Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(globepay_id),
+                    redirection_data: None,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                    network_txn_id: None,
+                }),
+                ..item.data
+            })
         } else {
+            let retry_strategy =
+                GlobepayMerchantConfig::try_from(&item.data.connector_meta_data)?.retry_strategy;
+            // A retried create call arrives as a brand-new request with no
+            // attempt history of its own to consult, so a transient code is
+            // checked against the retry budget as if no attempt had been spent
+            // yet (only a `max_attempts` of 0 fails it outright here); our own
+            // idempotent `partner_order_id` (see `GlobepayPaymentsRequest`)
+            // makes resending safe, and the `DuplicateOrderId` branch above
+            // resumes it cleanly once Globepay has actually created the order.
+            let status = if retry_strategy.should_retry(&item.response.return_code, 0) {
+                enums::AttemptStatus::Pending
+            } else {
+                enums::AttemptStatus::Failure //As this connector gives 200 in failed scenarios . if return_code is not success status is mapped to failure. ref = "https://pay.globepay.co/docs/en/#api-QRCode-NewQRCode"
+            };
             Ok(Self {
-                status: enums::AttemptStatus::Failure, //As this connector gives 200 in failed scenarios . if return_code is not success status is mapped to failure. ref = "https://pay.globepay.co/docs/en/#api-QRCode-NewQRCode"
+                status,
                 response: Err(types::ErrorResponse {
                     code: item.response.return_code.to_string(),
                     message: item.response.return_code.to_string(),
@@ -205,14 +443,72 @@ impl From<GlobepayPaymentPsyncStatus> for enums::AttemptStatus {
     }
 }
 
-impl<F, T>
-    TryFrom<types::ResponseRouterData<F, GlobepaySyncResponse, T, types::PaymentsResponseData>>
-    for types::RouterData<F, T, types::PaymentsResponseData>
+/// Body of Globepay's asynchronous payment-result notification, posted once the
+/// shopper finishes paying a QR code created earlier. Lets the payment settle
+/// without polling `GlobepaySyncResponse` in a loop.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GlobepayWebhookBody {
+    pub order_id: String,
+    pub result_code: GlobepayPaymentPsyncStatus,
+    pub sign: String,
+    pub time: i64,
+    pub nonce: Option<String>,
+}
+
+impl GlobepayWebhookBody {
+    /// Verifies `sign` against the same canonical-string scheme the connector
+    /// uses to sign outbound requests (see [`signing`]).
+    pub fn is_signature_valid(&self, auth: &GlobepayAuthType) -> bool {
+        signing::verify_signature(auth, self.time, self.nonce.as_deref(), &self.sign)
+    }
+}
+
+impl From<&GlobepayPaymentPsyncStatus> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(status: &GlobepayPaymentPsyncStatus) -> Self {
+        match status {
+            GlobepayPaymentPsyncStatus::PaySuccess => Self::PaymentIntentSuccess,
+            GlobepayPaymentPsyncStatus::PayFail | GlobepayPaymentPsyncStatus::Closed => {
+                Self::PaymentIntentFailure
+            }
+            GlobepayPaymentPsyncStatus::CreateFail | GlobepayPaymentPsyncStatus::Paying => {
+                Self::EventNotSupported
+            }
+        }
+    }
+}
+
+/// Still "Paying" past the QR's own expiry means the shopper never finished
+/// paying; stop treating it as pending instead of polling forever. Split out
+/// so the expiry check can be exercised without a full `RouterData`.
+fn is_qr_expired(
+    status: &GlobepayPaymentPsyncStatus,
+    expire_time: Option<i64>,
+    now: i64,
+) -> bool {
+    matches!(status, GlobepayPaymentPsyncStatus::Paying)
+        && expire_time.is_some_and(|expire_time| now >= expire_time)
+}
+
+impl TryFrom<types::PaymentsSyncResponseRouterData<GlobepaySyncResponse>>
+    for types::PaymentsSyncRouterData
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::ResponseRouterData<F, GlobepaySyncResponse, T, types::PaymentsResponseData>,
+        item: types::PaymentsSyncResponseRouterData<GlobepaySyncResponse>,
     ) -> Result<Self, Self::Error> {
+        // Globepay's order-query endpoint doesn't echo `expire_time` (or any
+        // retry bookkeeping) back on every poll, so whatever the create call
+        // (or a previous poll) persisted into `connector_metadata` is what this
+        // psync has to go on; carried forward below so the next poll still has
+        // it too.
+        let stored_metadata: Option<GlobepayConnectorMetadata> = item
+            .data
+            .request
+            .connector_meta
+            .clone()
+            .map(serde_json::from_value)
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
         if item.response.return_code == GlobepayReturnCode::Success {
             let globepay_status = item
                 .response
@@ -222,28 +518,85 @@ impl<F, T>
                 .response
                 .order_id
                 .ok_or(errors::ConnectorError::ResponseHandlingFailed)?;
+            let is_expired = is_qr_expired(
+                &globepay_status,
+                stored_metadata.as_ref().and_then(|metadata| metadata.expire_time),
+                time::OffsetDateTime::now_utc().unix_timestamp(),
+            );
+            let status = if is_expired {
+                // A timed-out QR wasn't rejected by Globepay and nothing about
+                // the attempt itself failed — the shopper simply never paid
+                // before the code lapsed. That's an abandonment, not a
+                // processing error, so it maps to `Voided` rather than
+                // `Failure` (which is reserved for PayFail/CreateFail/Closed
+                // and the default branch below, where Globepay did report a
+                // real failure).
+                enums::AttemptStatus::Voided
+            } else {
+                enums::AttemptStatus::from(globepay_status)
+            };
+            let connector_metadata = stored_metadata
+                .map(|metadata| {
+                    common_utils::ext_traits::Encode::<GlobepayConnectorMetadata>::encode_to_value(
+                        &metadata,
+                    )
+                })
+                .transpose()
+                .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
             Ok(Self {
-                status: enums::AttemptStatus::from(globepay_status),
+                status,
                 response: Ok(types::PaymentsResponseData::TransactionResponse {
                     resource_id: types::ResponseId::ConnectorTransactionId(globepay_id),
                     redirection_data: None,
                     mandate_reference: None,
-                    connector_metadata: None,
+                    connector_metadata,
                     network_txn_id: None,
                 }),
                 ..item.data
             })
         } else {
-            Ok(Self {
-                status: enums::AttemptStatus::Failure,
-                response: Err(types::ErrorResponse {
-                    code: item.response.return_code.to_string(),
-                    message: item.response.return_code.to_string(),
-                    reason: item.response.return_msg,
-                    status_code: item.http_code,
-                }),
-                ..item.data
-            })
+            let retry_strategy =
+                GlobepayMerchantConfig::try_from(&item.data.connector_meta_data)?.retry_strategy;
+            let attempts_made = stored_metadata
+                .as_ref()
+                .map_or(0, |metadata| metadata.retry_attempts);
+            if retry_strategy.should_retry(&item.response.return_code, attempts_made) {
+                // Bump the retry counter and keep polling instead of failing
+                // outright; spacing between attempts comes from the router's
+                // own poll cadence, there's no backoff computed here.
+                let connector_metadata = stored_metadata
+                    .map(|metadata| GlobepayConnectorMetadata {
+                        retry_attempts: metadata.retry_attempts + 1,
+                        ..metadata
+                    })
+                    .map(|metadata| {
+                        common_utils::ext_traits::Encode::<GlobepayConnectorMetadata>::encode_to_value(&metadata)
+                    })
+                    .transpose()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+                Ok(Self {
+                    status: enums::AttemptStatus::Pending,
+                    response: Ok(types::PaymentsResponseData::TransactionResponse {
+                        resource_id: item.data.request.connector_transaction_id.clone(),
+                        redirection_data: None,
+                        mandate_reference: None,
+                        connector_metadata,
+                        network_txn_id: None,
+                    }),
+                    ..item.data
+                })
+            } else {
+                Ok(Self {
+                    status: enums::AttemptStatus::Failure,
+                    response: Err(types::ErrorResponse {
+                        code: item.response.return_code.to_string(),
+                        message: item.response.return_code.to_string(),
+                        reason: item.response.return_msg,
+                        status_code: item.http_code,
+                    }),
+                    ..item.data
+                })
+            }
         }
     }
 }
@@ -326,4 +679,60 @@ pub struct GlobepayErrorResponse {
     pub return_msg: String,
     pub return_code: GlobepayReturnCode,
     pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_still_paying_past_expiry_is_expired() {
+        assert!(is_qr_expired(
+            &GlobepayPaymentPsyncStatus::Paying,
+            Some(1_000),
+            1_001,
+        ));
+    }
+
+    #[test]
+    fn qr_still_paying_before_expiry_is_not_expired() {
+        assert!(!is_qr_expired(
+            &GlobepayPaymentPsyncStatus::Paying,
+            Some(1_000),
+            999,
+        ));
+    }
+
+    #[test]
+    fn qr_without_a_persisted_expiry_never_expires() {
+        assert!(!is_qr_expired(&GlobepayPaymentPsyncStatus::Paying, None, i64::MAX));
+    }
+
+    #[test]
+    fn settled_status_is_never_considered_expired() {
+        assert!(!is_qr_expired(
+            &GlobepayPaymentPsyncStatus::PaySuccess,
+            Some(1_000),
+            1_001,
+        ));
+    }
+
+    #[test]
+    fn transient_code_is_retried_within_the_attempt_budget() {
+        let strategy = GlobepayRetryStrategy::default();
+        assert!(strategy.should_retry(&GlobepayReturnCode::Systemerror, 0));
+        assert!(strategy.should_retry(&GlobepayReturnCode::SignTimeout, strategy.max_attempts - 1));
+    }
+
+    #[test]
+    fn transient_code_stops_retrying_once_attempts_are_exhausted() {
+        let strategy = GlobepayRetryStrategy::default();
+        assert!(!strategy.should_retry(&GlobepayReturnCode::Systemerror, strategy.max_attempts));
+    }
+
+    #[test]
+    fn terminal_code_is_never_retried() {
+        let strategy = GlobepayRetryStrategy::default();
+        assert!(!strategy.should_retry(&GlobepayReturnCode::ParamInvalid, 0));
+    }
 }
\ No newline at end of file